@@ -1,20 +1,33 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
+use directories::ProjectDirs;
 use raylib::{core::window, ffi, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::{
-    Arc,
     atomic::{AtomicBool, Ordering},
+    Arc,
 };
 
 #[cfg(target_os = "linux")]
 use ksni::{blocking::TrayMethods, menu::StandardItem};
 
+/// The stock DVD logo, embedded so the binary runs from any install location
+/// without needing the source tree alongside it.
+const DEFAULT_LOGO_BYTES: &[u8] = include_bytes!("dvd.png");
+const DEFAULT_LOGO_FILETYPE: &str = ".png";
+
 const LOGO_DRAW_WIDTH: f32 = 240.0;
 const SPEED_X: f32 = 240.0;
 const SPEED_Y: f32 = 180.0;
 const CORNER_FLASH_FRAMES: u8 = 12;
 const DEFAULT_CORNER_MARGIN: i32 = 5;
+const DEFAULT_TRAIL_FADE: f32 = 0.08;
+const DEFAULT_SPEED: f32 = 1.0;
 const MAX_STEP_PIXELS: f32 = 16.0;
 const BOUNCE_JITTER_DEGREES: f32 = 0.45;
+const SETTINGS_FILE_NAME: &str = "settings.toml";
+const SPEED_STEP: f32 = 0.1;
+const CORNER_STEP: i32 = 1;
 
 #[derive(Parser, Debug)]
 #[command(name = "raydvd", version, about = "Transparent bouncing DVD overlay")]
@@ -22,23 +35,96 @@ struct Args {
     #[arg(
         short = 's',
         long,
-        default_value_t = 1.0,
         value_parser = parse_speed_multiplier,
-        help = "Multiply logo speed by this value (> 0)"
+        help = "Multiply logo speed by this value (> 0); overrides the settings file"
     )]
-    speed: f32,
+    speed: Option<f32>,
 
     #[arg(
         short = 'c',
         long,
-        default_value_t = DEFAULT_CORNER_MARGIN,
         value_parser = parse_corner_margin,
-        help = "Corner hit margin in pixels (>= 0)"
+        help = "Corner hit margin in pixels (>= 0); overrides the settings file"
     )]
-    corner: i32,
+    corner: Option<i32>,
 
-    #[arg(short = 't', long, help = "Draw center-point trace path")]
+    #[arg(
+        short = 't',
+        long,
+        help = "Draw center-point trace path, even if disabled in the settings file"
+    )]
     trace: bool,
+
+    #[arg(
+        long,
+        value_parser = parse_trail_fade,
+        help = "Trail decay rate per frame for --trace, 0..1; overrides the settings file"
+    )]
+    trail_fade: Option<f32>,
+
+    #[arg(
+        short = 'l',
+        long,
+        value_name = "PATH",
+        value_parser = parse_logo_path,
+        help = "Load a custom logo image instead of the bundled DVD logo (PNG/BMP/JPG)"
+    )]
+    logo: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Skip the black->white recolor pass, keeping the image's own colors"
+    )]
+    no_recolor: bool,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ShaderEffect::None,
+        help = "Post-processing effect to render the overlay through"
+    )]
+    shader: ShaderEffect,
+
+    #[arg(
+        long,
+        value_enum,
+        help = "Show a stats HUD (corner hits, runtime, speed, FPS) in a screen corner"
+    )]
+    hud: Option<HudCorner>,
+
+    #[arg(
+        long,
+        default_value_t = 1,
+        value_parser = parse_logo_count,
+        help = "Spawn this many independent bouncing logos (>= 1)"
+    )]
+    count: usize,
+
+    #[arg(
+        long,
+        help = "Elastically swap velocities when two logos overlap (requires --count > 1)"
+    )]
+    collide: bool,
+}
+
+/// Built-in GLSL post-processing effects selectable via `--shader`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum ShaderEffect {
+    /// Render the logo and trail directly, with no post-processing.
+    None,
+    /// Two-pass Gaussian blur additively blended back for a corner-hit glow.
+    Bloom,
+    /// Per-channel UV offset that grows with corner-hit flash strength.
+    ChromaticAberration,
+}
+
+/// Screen corner to anchor the `--hud` stats overlay in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum HudCorner {
+    Tl,
+    Tr,
+    Bl,
+    Br,
 }
 
 fn parse_speed_multiplier(input: &str) -> Result<f32, String> {
@@ -63,6 +149,282 @@ fn parse_corner_margin(input: &str) -> Result<i32, String> {
     }
 }
 
+fn parse_logo_count(input: &str) -> Result<usize, String> {
+    let value: usize = input
+        .parse()
+        .map_err(|_| format!("'{input}' is not a valid integer"))?;
+    if value >= 1 {
+        Ok(value)
+    } else {
+        Err("count must be an integer >= 1".to_string())
+    }
+}
+
+fn parse_trail_fade(input: &str) -> Result<f32, String> {
+    let value: f32 = input
+        .parse()
+        .map_err(|_| format!("'{input}' is not a valid float"))?;
+    if (0.0..=1.0).contains(&value) {
+        Ok(value)
+    } else {
+        Err("trail fade must be between 0 and 1".to_string())
+    }
+}
+
+fn parse_logo_path(input: &str) -> Result<PathBuf, String> {
+    let path = PathBuf::from(input);
+    match std::fs::metadata(&path) {
+        Ok(metadata) if metadata.is_file() => Ok(path),
+        Ok(_) => Err(format!("'{input}' is not a file")),
+        Err(err) => Err(format!("can't read logo image '{input}': {err}")),
+    }
+}
+
+/// Creates a screen-sized render texture to accumulate the fading trail,
+/// cleared to fully transparent so it composites cleanly over the
+/// transparent window background.
+fn create_trail_texture(
+    rl: &mut RaylibHandle,
+    thread: &RaylibThread,
+    width: i32,
+    height: i32,
+) -> RenderTexture2D {
+    let mut texture = rl
+        .load_render_texture(thread, width.max(1) as u32, height.max(1) as u32)
+        .expect("failed to create trail render texture");
+    {
+        let mut mode = rl.begin_texture_mode(thread, &mut texture);
+        mode.clear_background(Color::new(0, 0, 0, 0));
+    }
+    texture
+}
+
+const BLUR_FS: &str = r#"#version 330
+in vec2 fragTexCoord;
+out vec4 finalColor;
+
+uniform sampler2D texture0;
+uniform vec2 texelSize;
+uniform vec2 direction;
+
+void main() {
+    float weights[5] = float[](0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+    vec4 color = texture(texture0, fragTexCoord) * weights[0];
+    for (int i = 1; i < 5; i++) {
+        vec2 offset = direction * texelSize * float(i);
+        color += texture(texture0, fragTexCoord + offset) * weights[i];
+        color += texture(texture0, fragTexCoord - offset) * weights[i];
+    }
+    finalColor = color;
+}
+"#;
+
+const CHROMATIC_ABERRATION_FS: &str = r#"#version 330
+in vec2 fragTexCoord;
+out vec4 finalColor;
+
+uniform sampler2D texture0;
+uniform float time;
+uniform float strength;
+
+void main() {
+    vec2 offset = vec2(strength * (0.006 + 0.004 * sin(time * 6.0)), 0.0);
+    vec4 base = texture(texture0, fragTexCoord);
+    float r = texture(texture0, fragTexCoord + offset).r;
+    float b = texture(texture0, fragTexCoord - offset).b;
+    finalColor = vec4(r, base.g, b, base.a);
+}
+"#;
+
+/// GPU resources for a single `--shader` effect.
+enum ShaderPipeline {
+    Bloom {
+        blur_shader: Shader,
+        direction_loc: i32,
+        texel_size_loc: i32,
+        ping: RenderTexture2D,
+        pong: RenderTexture2D,
+    },
+    ChromaticAberration {
+        shader: Shader,
+        time_loc: i32,
+        strength_loc: i32,
+    },
+}
+
+/// The offscreen scene buffer a `ShaderPipeline` reads from, bundled
+/// together since both are recreated whenever the resolution changes.
+struct PostProcessing {
+    scene: RenderTexture2D,
+    effect: ShaderPipeline,
+}
+
+/// Builds the post-processing pipeline for `effect`, or `None` for
+/// `ShaderEffect::None` or if the shader fails to compile on this GPU/driver
+/// (falling back to the existing direct-draw path).
+fn build_post_processing(
+    rl: &mut RaylibHandle,
+    thread: &RaylibThread,
+    effect: ShaderEffect,
+    width: i32,
+    height: i32,
+) -> Option<PostProcessing> {
+    let w = width.max(1) as u32;
+    let h = height.max(1) as u32;
+
+    let pipeline = match effect {
+        ShaderEffect::None => return None,
+        ShaderEffect::Bloom => {
+            let mut blur_shader = rl.load_shader_from_memory(thread, None, Some(BLUR_FS));
+            if !blur_shader.is_shader_valid() {
+                eprintln!(
+                    "warning: bloom shader failed to compile; falling back to direct rendering"
+                );
+                return None;
+            }
+            let direction_loc = blur_shader.get_shader_location("direction");
+            let texel_size_loc = blur_shader.get_shader_location("texelSize");
+            let ping = rl
+                .load_render_texture(thread, w, h)
+                .expect("failed to create bloom ping buffer");
+            let pong = rl
+                .load_render_texture(thread, w, h)
+                .expect("failed to create bloom pong buffer");
+            ShaderPipeline::Bloom {
+                blur_shader,
+                direction_loc,
+                texel_size_loc,
+                ping,
+                pong,
+            }
+        }
+        ShaderEffect::ChromaticAberration => {
+            let shader = rl.load_shader_from_memory(thread, None, Some(CHROMATIC_ABERRATION_FS));
+            if !shader.is_shader_valid() {
+                eprintln!(
+                    "warning: chromatic-aberration shader failed to compile; falling back to direct rendering"
+                );
+                return None;
+            }
+            let time_loc = shader.get_shader_location("time");
+            let strength_loc = shader.get_shader_location("strength");
+            ShaderPipeline::ChromaticAberration {
+                shader,
+                time_loc,
+                strength_loc,
+            }
+        }
+    };
+
+    let scene = rl
+        .load_render_texture(thread, w, h)
+        .expect("failed to create post-processing scene buffer");
+    Some(PostProcessing {
+        scene,
+        effect: pipeline,
+    })
+}
+
+/// Blits the fading trail (if any) and the logo into whatever is currently
+/// being drawn to, shared by the direct-draw path and the post-processing
+/// scene capture.
+fn draw_scene(
+    d: &mut impl RaylibDraw,
+    trail_texture: Option<&RenderTexture2D>,
+    logo_texture: &Texture2D,
+    logos: &[(Vector2, Color)],
+    logo_scale: f32,
+    screen_width: i32,
+    screen_height: i32,
+) {
+    if let Some(trail) = trail_texture {
+        let source = Rectangle::new(0.0, 0.0, screen_width as f32, -(screen_height as f32));
+        let mut blend = d.begin_blend_mode(BlendMode::BLEND_ALPHA);
+        blend.draw_texture_rec(
+            trail.texture(),
+            source,
+            Vector2::new(0.0, 0.0),
+            Color::WHITE,
+        );
+    }
+    for (pos, color) in logos {
+        d.draw_texture_ex(logo_texture, *pos, 0.0, logo_scale, *color);
+    }
+}
+
+const HUD_MARGIN: i32 = 12;
+const HUD_LINE_HEIGHT: i32 = 18;
+const HUD_FONT_SIZE: i32 = 16;
+
+/// The numbers shown on the `--hud` overlay, bundled so `draw_hud` doesn't
+/// trip `clippy::too_many_arguments`.
+struct HudStats {
+    corner_hits: u32,
+    runtime_secs: f32,
+    speed: f32,
+    fps: u32,
+}
+
+/// Draws the `--hud` stats overlay (corner-hit count, runtime, speed, FPS) in
+/// `corner`, with a dark shadow behind each line so it stays legible over
+/// arbitrary desktop backgrounds.
+fn draw_hud(
+    d: &mut impl RaylibDraw,
+    corner: HudCorner,
+    stats: &HudStats,
+    screen_width: i32,
+    screen_height: i32,
+) {
+    let lines = [
+        format!("corner hits: {}", stats.corner_hits),
+        format!("runtime: {:.0}s", stats.runtime_secs),
+        format!("speed: {:.2}x", stats.speed),
+        format!("fps: {}", stats.fps),
+    ];
+
+    let widest = lines
+        .iter()
+        .map(|line| {
+            let c_line = std::ffi::CString::new(line.as_str()).unwrap();
+            unsafe { ffi::MeasureText(c_line.as_ptr(), HUD_FONT_SIZE) }
+        })
+        .max()
+        .unwrap_or(0);
+    let block_height = HUD_LINE_HEIGHT * lines.len() as i32;
+
+    let x = match corner {
+        HudCorner::Tl | HudCorner::Bl => HUD_MARGIN,
+        HudCorner::Tr | HudCorner::Br => screen_width - HUD_MARGIN - widest,
+    };
+    let y0 = match corner {
+        HudCorner::Tl | HudCorner::Tr => HUD_MARGIN,
+        HudCorner::Bl | HudCorner::Br => screen_height - HUD_MARGIN - block_height,
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        let y = y0 + i as i32 * HUD_LINE_HEIGHT;
+        d.draw_text(line, x + 1, y + 1, HUD_FONT_SIZE, Color::new(0, 0, 0, 180));
+        d.draw_text(line, x, y, HUD_FONT_SIZE, Color::WHITE);
+    }
+}
+
+/// Loads the logo image, either from `--logo <PATH>` or the embedded default,
+/// letting raylib sniff the format from the file extension (or the fixed
+/// `.png` hint for the embedded bytes).
+fn load_logo_image(path: Option<&PathBuf>) -> Image {
+    match path {
+        Some(path) => {
+            let path_str = path.to_str().unwrap_or_else(|| {
+                panic!("logo path {path:?} is not valid UTF-8");
+            });
+            Image::load_image(path_str)
+                .unwrap_or_else(|err| panic!("failed to load logo image {path_str}: {err}"))
+        }
+        None => Image::load_image_from_mem(DEFAULT_LOGO_FILETYPE, DEFAULT_LOGO_BYTES)
+            .expect("failed to decode embedded default logo"),
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq)]
 enum LogoColor {
     Red,
@@ -119,10 +481,121 @@ impl LogoColor {
     }
 }
 
-fn random_logo_color(excluding: LogoColor) -> LogoColor {
+/// A named set of `LogoColor`s to cycle through on bounce, selectable via
+/// the settings file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Palette {
+    Vivid,
+    Mono,
+    Pastel,
+}
+
+impl Palette {
+    fn colors(self) -> &'static [LogoColor] {
+        const PASTEL: [LogoColor; 5] = [
+            LogoColor::Pink,
+            LogoColor::Cyan,
+            LogoColor::Lime,
+            LogoColor::Violet,
+            LogoColor::Gold,
+        ];
+        const MONO: [LogoColor; 1] = [LogoColor::White];
+        match self {
+            Palette::Vivid => &LogoColor::ALL,
+            Palette::Mono => &MONO,
+            Palette::Pastel => &PASTEL,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::Vivid
+    }
+}
+
+/// Runtime-tunable overlay settings: loaded from a config file at startup
+/// (with CLI args taking precedence), adjustable live via hotkeys, and
+/// written back on quit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct Settings {
+    speed: f32,
+    corner_margin: i32,
+    trace: bool,
+    trail_fade: f32,
+    palette: Palette,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            speed: DEFAULT_SPEED,
+            corner_margin: DEFAULT_CORNER_MARGIN,
+            trace: false,
+            trail_fade: DEFAULT_TRAIL_FADE,
+            palette: Palette::default(),
+        }
+    }
+}
+
+fn settings_path() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "raydvd").map(|dirs| dirs.config_dir().join(SETTINGS_FILE_NAME))
+}
+
+/// Loads settings from the platform config dir, falling back to defaults if
+/// the file is missing or fails to parse.
+fn load_settings() -> Settings {
+    let Some(path) = settings_path() else {
+        return Settings::default();
+    };
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Settings::default();
+    };
+    toml::from_str(&contents).unwrap_or_else(|err| {
+        eprintln!(
+            "warning: failed to parse settings file {}: {err}; using defaults",
+            path.display()
+        );
+        Settings::default()
+    })
+}
+
+/// Writes settings back to the platform config dir, creating it if needed.
+fn save_settings(settings: &Settings) {
+    let Some(path) = settings_path() else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        if let Err(err) = std::fs::create_dir_all(parent) {
+            eprintln!(
+                "warning: failed to create config dir {}: {err}",
+                parent.display()
+            );
+            return;
+        }
+    }
+    match toml::to_string_pretty(settings) {
+        Ok(contents) => {
+            if let Err(err) = std::fs::write(&path, contents) {
+                eprintln!(
+                    "warning: failed to write settings file {}: {err}",
+                    path.display()
+                );
+            }
+        }
+        Err(err) => eprintln!("warning: failed to serialize settings: {err}"),
+    }
+}
+
+fn random_logo_color(excluding: LogoColor, palette: &[LogoColor]) -> LogoColor {
+    if palette.len() <= 1 {
+        return palette.first().copied().unwrap_or(excluding);
+    }
     loop {
-        let idx = unsafe { ffi::GetRandomValue(0, (LogoColor::ALL.len() - 1) as i32) } as usize;
-        let choice = LogoColor::ALL[idx];
+        let idx = unsafe { ffi::GetRandomValue(0, (palette.len() - 1) as i32) } as usize;
+        let choice = palette[idx];
         if choice != excluding {
             return choice;
         }
@@ -149,9 +622,216 @@ fn apply_bounce_jitter(vel: &mut Vector2) {
     }
 }
 
+/// Screen and logo geometry shared by every `Logo::step` call in a frame,
+/// bundled so the method doesn't trip `clippy::too_many_arguments`.
+#[derive(Copy, Clone)]
+struct Bounds {
+    screen_width: i32,
+    screen_height: i32,
+    logo_width: f32,
+    logo_height: f32,
+}
+
+/// A single bouncing logo's motion and per-entity flash state, extracted
+/// from the formerly single-entity main loop so `--count` can drive any
+/// number of them independently.
+struct Logo {
+    pos: Vector2,
+    vel: Vector2,
+    color: LogoColor,
+    corner_flash_frames: u8,
+    corner_flash_step: usize,
+    prev_center: Vector2,
+}
+
+impl Logo {
+    fn new(
+        pos: Vector2,
+        vel: Vector2,
+        color: LogoColor,
+        logo_width: f32,
+        logo_height: f32,
+    ) -> Self {
+        let prev_center = Vector2::new(pos.x + logo_width * 0.5, pos.y + logo_height * 0.5);
+        Logo {
+            pos,
+            vel,
+            color,
+            corner_flash_frames: 0,
+            corner_flash_step: 0,
+            prev_center,
+        }
+    }
+
+    fn center(&self, logo_width: f32, logo_height: f32) -> Vector2 {
+        Vector2::new(
+            self.pos.x + logo_width * 0.5,
+            self.pos.y + logo_height * 0.5,
+        )
+    }
+
+    /// Advances this logo by one frame, sub-stepping so it can't tunnel
+    /// through a corner at high speed, and updates its color/flash state.
+    /// Returns whether a corner was hit this frame.
+    fn step(
+        &mut self,
+        bounds: &Bounds,
+        dt: f32,
+        corner_margin: f32,
+        palette: &[LogoColor],
+    ) -> bool {
+        let Bounds {
+            screen_width,
+            screen_height,
+            logo_width,
+            logo_height,
+        } = *bounds;
+
+        let distance = self.vel.x.abs().max(self.vel.y.abs()) * dt;
+        let steps = (distance / MAX_STEP_PIXELS).ceil().max(1.0) as i32;
+        let sub_dt = dt / steps as f32;
+        let mut bounced_x_any = false;
+        let mut bounced_y_any = false;
+        let mut corner_hit = false;
+
+        for _ in 0..steps {
+            let mut bounced_x = false;
+            let mut bounced_y = false;
+
+            self.pos.x += self.vel.x * sub_dt;
+            self.pos.y += self.vel.y * sub_dt;
+
+            if self.pos.x <= 0.0 {
+                self.pos.x = 0.0;
+                self.vel.x = self.vel.x.abs();
+                bounced_x = true;
+            } else if self.pos.x + logo_width >= screen_width as f32 {
+                self.pos.x = screen_width as f32 - logo_width;
+                self.vel.x = -self.vel.x.abs();
+                bounced_x = true;
+            }
+
+            if self.pos.y <= 0.0 {
+                self.pos.y = 0.0;
+                self.vel.y = self.vel.y.abs();
+                bounced_y = true;
+            } else if self.pos.y + logo_height >= screen_height as f32 {
+                self.pos.y = screen_height as f32 - logo_height;
+                self.vel.y = -self.vel.y.abs();
+                bounced_y = true;
+            }
+
+            if bounced_x {
+                bounced_x_any = true;
+            }
+            if bounced_y {
+                bounced_y_any = true;
+            }
+
+            let near_top = self.pos.y <= corner_margin;
+            let near_bottom = self.pos.y + logo_height >= screen_height as f32 - corner_margin;
+            let near_left = self.pos.x <= corner_margin;
+            let near_right = self.pos.x + logo_width >= screen_width as f32 - corner_margin;
+            let near_corner = (near_left || near_right) && (near_top || near_bottom);
+
+            if (bounced_x && bounced_y) || (near_corner && (bounced_x || bounced_y)) {
+                corner_hit = true;
+            }
+        }
+
+        if corner_hit {
+            self.color = LogoColor::Gold;
+            self.corner_flash_frames = CORNER_FLASH_FRAMES;
+            self.corner_flash_step = 0;
+        } else if bounced_x_any || bounced_y_any {
+            self.color = random_logo_color(self.color, palette);
+        }
+
+        if bounced_x_any || bounced_y_any {
+            apply_bounce_jitter(&mut self.vel);
+        }
+
+        corner_hit
+    }
+
+    /// Returns the color to draw this frame, advancing the corner-flash
+    /// cycle by one step if a flash is in progress.
+    fn draw_color(&mut self) -> Color {
+        if self.corner_flash_frames > 0 {
+            let flash_color =
+                LogoColor::CORNER_FLASH[self.corner_flash_step % LogoColor::CORNER_FLASH.len()];
+            self.corner_flash_frames -= 1;
+            self.corner_flash_step += 1;
+            flash_color.color()
+        } else {
+            self.color.color()
+        }
+    }
+}
+
+/// Spawns `count` logos at randomized positions with randomized headings
+/// (same speed magnitude as the single-logo case) and randomized starting
+/// colors from `palette`.
+fn spawn_logos(
+    count: usize,
+    screen_width: i32,
+    screen_height: i32,
+    logo_width: f32,
+    logo_height: f32,
+    speed: f32,
+    palette: &[LogoColor],
+) -> Vec<Logo> {
+    let base_speed = (SPEED_X * SPEED_X + SPEED_Y * SPEED_Y).sqrt() * speed;
+    let max_x = (screen_width as f32 - logo_width).max(0.0) as i32;
+    let max_y = (screen_height as f32 - logo_height).max(0.0) as i32;
+
+    (0..count)
+        .map(|_| {
+            let x = unsafe { ffi::GetRandomValue(0, max_x) } as f32;
+            let y = unsafe { ffi::GetRandomValue(0, max_y) } as f32;
+            let pos = Vector2::new(x, y);
+            let heading = unsafe { ffi::GetRandomValue(0, 35999) } as f32 / 100.0;
+            let (sin_a, cos_a) = heading.to_radians().sin_cos();
+            let vel = Vector2::new(base_speed * cos_a, base_speed * sin_a);
+            let color_idx = unsafe { ffi::GetRandomValue(0, (palette.len() as i32 - 1).max(0)) };
+            let color = palette
+                .get(color_idx as usize)
+                .copied()
+                .unwrap_or(LogoColor::Cyan);
+            Logo::new(pos, vel, color, logo_width, logo_height)
+        })
+        .collect()
+}
+
+/// When `--collide` is set, elastically swaps velocities between any two
+/// overlapping logos that are still approaching each other. Gating on
+/// closing velocity (rather than overlap alone) means the swap fires once
+/// per collision instead of every frame the (wide, slow-moving) logos
+/// spend overlapping, which would otherwise make them oscillate in place.
+fn resolve_collisions(logos: &mut [Logo], logo_width: f32, logo_height: f32) {
+    for i in 0..logos.len() {
+        for j in (i + 1)..logos.len() {
+            let (left, right) = logos.split_at_mut(j);
+            let a = &mut left[i];
+            let b = &mut right[0];
+            let overlap = a.pos.x < b.pos.x + logo_width
+                && a.pos.x + logo_width > b.pos.x
+                && a.pos.y < b.pos.y + logo_height
+                && a.pos.y + logo_height > b.pos.y;
+            let rel_pos = Vector2::new(a.pos.x - b.pos.x, a.pos.y - b.pos.y);
+            let rel_vel = Vector2::new(a.vel.x - b.vel.x, a.vel.y - b.vel.y);
+            let closing = rel_pos.x * rel_vel.x + rel_pos.y * rel_vel.y < 0.0;
+            if overlap && closing {
+                std::mem::swap(&mut a.vel, &mut b.vel);
+            }
+        }
+    }
+}
+
 #[cfg(target_os = "linux")]
 struct TrayApp {
     running: Arc<AtomicBool>,
+    toggle_trace_requested: Arc<AtomicBool>,
 }
 
 #[cfg(target_os = "linux")]
@@ -179,24 +859,49 @@ impl ksni::Tray for TrayApp {
     }
 
     fn menu(&self) -> Vec<ksni::MenuItem<Self>> {
-        vec![ksni::MenuItem::Standard(StandardItem {
-            label: "Quit".into(),
-            activate: Box::new(|this: &mut Self| {
-                this.running.store(false, Ordering::Relaxed);
+        vec![
+            ksni::MenuItem::Standard(StandardItem {
+                label: "Toggle Trace".into(),
+                activate: Box::new(|this: &mut Self| {
+                    this.toggle_trace_requested.store(true, Ordering::Relaxed);
+                }),
+                ..Default::default()
             }),
-            ..Default::default()
-        })]
+            ksni::MenuItem::Standard(StandardItem {
+                label: "Quit".into(),
+                activate: Box::new(|this: &mut Self| {
+                    this.running.store(false, Ordering::Relaxed);
+                }),
+                ..Default::default()
+            }),
+        ]
     }
 }
 
 fn main() {
     let args = Args::parse();
 
+    let mut settings = load_settings();
+    if let Some(speed) = args.speed {
+        settings.speed = speed;
+    }
+    if let Some(corner) = args.corner {
+        settings.corner_margin = corner;
+    }
+    if args.trace {
+        settings.trace = true;
+    }
+    if let Some(trail_fade) = args.trail_fade {
+        settings.trail_fade = trail_fade;
+    }
+
     let running = Arc::new(AtomicBool::new(true));
+    let toggle_trace_requested = Arc::new(AtomicBool::new(false));
     #[cfg(target_os = "linux")]
     let _tray_handle = {
         let tray = TrayApp {
             running: Arc::clone(&running),
+            toggle_trace_requested: Arc::clone(&toggle_trace_requested),
         };
         match tray.spawn() {
             Ok(handle) => Some(handle),
@@ -214,17 +919,21 @@ fn main() {
         .transparent()
         .build();
 
-    let logo_path = format!("{}/src/dvd.png", env!("CARGO_MANIFEST_DIR"));
-    let mut logo_image = Image::load_image(&logo_path)
-        .expect("failed to load src/dvd.png; ensure the file exists and is valid PNG");
-    logo_image.color_replace(Color::BLACK, Color::WHITE);
+    let mut logo_image = load_logo_image(args.logo.as_ref());
+    if !args.no_recolor {
+        logo_image.color_replace(Color::BLACK, Color::WHITE);
+    }
+    let logo_source = match &args.logo {
+        Some(path) => format!("custom logo {}", path.display()),
+        None => "embedded default logo".to_string(),
+    };
     let logo_texture = rl
         .load_texture_from_image(&thread, &logo_image)
-        .expect("failed to create texture from src/dvd.png");
+        .unwrap_or_else(|err| panic!("failed to create texture from {logo_source}: {err}"));
     let logo_scale = LOGO_DRAW_WIDTH / logo_texture.width() as f32;
     let logo_width = logo_texture.width() as f32 * logo_scale;
     let logo_height = logo_texture.height() as f32 * logo_scale;
-    let mut logo_color = LogoColor::Cyan;
+    let logo_color = LogoColor::Cyan;
 
     rl.set_target_fps(60);
     unsafe {
@@ -245,20 +954,38 @@ fn main() {
     rl.set_window_position(0, 0);
     rl.set_window_size(screen_width, screen_height);
 
-    let mut pos = Vector2::new(
-        (screen_width as f32 - logo_width) * 0.5,
-        (screen_height as f32 - logo_height) * 0.5,
-    );
-    let mut vel = Vector2::new(SPEED_X * args.speed, SPEED_Y * args.speed);
-    let mut corner_flash_frames = 0u8;
-    let mut corner_flash_step = 0usize;
-    let mut trace_points: Vec<Vector2> = Vec::new();
-    if args.trace {
-        trace_points.push(Vector2::new(
-            pos.x + logo_width * 0.5,
-            pos.y + logo_height * 0.5,
-        ));
-    }
+    let mut logos = if args.count <= 1 {
+        let pos = Vector2::new(
+            (screen_width as f32 - logo_width) * 0.5,
+            (screen_height as f32 - logo_height) * 0.5,
+        );
+        let vel = Vector2::new(SPEED_X * settings.speed, SPEED_Y * settings.speed);
+        vec![Logo::new(pos, vel, logo_color, logo_width, logo_height)]
+    } else {
+        spawn_logos(
+            args.count,
+            screen_width,
+            screen_height,
+            logo_width,
+            logo_height,
+            settings.speed,
+            settings.palette.colors(),
+        )
+    };
+    let mut corner_hit_count = 0u32;
+    let start_time = rl.get_time() as f32;
+    let mut trail_texture = if settings.trace {
+        Some(create_trail_texture(
+            &mut rl,
+            &thread,
+            screen_width,
+            screen_height,
+        ))
+    } else {
+        None
+    };
+    let mut post =
+        build_post_processing(&mut rl, &thread, args.shader, screen_width, screen_height);
 
     while running.load(Ordering::Relaxed) && !rl.window_should_close() {
         let ctrl_pressed = rl.is_key_down(KeyboardKey::KEY_LEFT_CONTROL)
@@ -268,6 +995,47 @@ fn main() {
             continue;
         }
 
+        if toggle_trace_requested.swap(false, Ordering::Relaxed) {
+            settings.trace = !settings.trace;
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_T) {
+            settings.trace = !settings.trace;
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_EQUAL) || rl.is_key_pressed(KeyboardKey::KEY_KP_ADD) {
+            let new_speed = settings.speed + SPEED_STEP;
+            for logo in &mut logos {
+                logo.vel.x = logo.vel.x * new_speed / settings.speed;
+                logo.vel.y = logo.vel.y * new_speed / settings.speed;
+            }
+            settings.speed = new_speed;
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_MINUS)
+            || rl.is_key_pressed(KeyboardKey::KEY_KP_SUBTRACT)
+        {
+            let new_speed = (settings.speed - SPEED_STEP).max(SPEED_STEP);
+            for logo in &mut logos {
+                logo.vel.x = logo.vel.x * new_speed / settings.speed;
+                logo.vel.y = logo.vel.y * new_speed / settings.speed;
+            }
+            settings.speed = new_speed;
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_LEFT_BRACKET) {
+            settings.corner_margin = (settings.corner_margin - CORNER_STEP).max(0);
+        }
+        if rl.is_key_pressed(KeyboardKey::KEY_RIGHT_BRACKET) {
+            settings.corner_margin += CORNER_STEP;
+        }
+        if settings.trace && trail_texture.is_none() {
+            trail_texture = Some(create_trail_texture(
+                &mut rl,
+                &thread,
+                screen_width,
+                screen_height,
+            ));
+        } else if !settings.trace && trail_texture.is_some() {
+            trail_texture = None;
+        }
+
         let current_monitor = window::get_current_monitor();
         if current_monitor != monitor {
             monitor = current_monitor;
@@ -275,108 +1043,213 @@ fn main() {
             screen_height = window::get_monitor_height(monitor).max(1);
             rl.set_window_position(0, 0);
             rl.set_window_size(screen_width, screen_height);
+            if settings.trace {
+                trail_texture = Some(create_trail_texture(
+                    &mut rl,
+                    &thread,
+                    screen_width,
+                    screen_height,
+                ));
+            }
+            post =
+                build_post_processing(&mut rl, &thread, args.shader, screen_width, screen_height);
         } else {
             screen_width = rl.get_screen_width().max(1);
             screen_height = rl.get_screen_height().max(1);
         }
 
         let dt = rl.get_frame_time();
-        let distance = vel.x.abs().max(vel.y.abs()) * dt;
-        let steps = (distance / MAX_STEP_PIXELS).ceil().max(1.0) as i32;
-        let sub_dt = dt / steps as f32;
-        let mut bounced_x_any = false;
-        let mut bounced_y_any = false;
-        let mut corner_hit = false;
-
-        for _ in 0..steps {
-            let mut bounced_x = false;
-            let mut bounced_y = false;
-
-            pos.x += vel.x * sub_dt;
-            pos.y += vel.y * sub_dt;
-
-            if pos.x <= 0.0 {
-                pos.x = 0.0;
-                vel.x = vel.x.abs();
-                bounced_x = true;
-            } else if pos.x + logo_width >= screen_width as f32 {
-                pos.x = screen_width as f32 - logo_width;
-                vel.x = -vel.x.abs();
-                bounced_x = true;
+        let corner_margin = settings.corner_margin as f32;
+        let bounds = Bounds {
+            screen_width,
+            screen_height,
+            logo_width,
+            logo_height,
+        };
+        for logo in &mut logos {
+            let corner_hit = logo.step(&bounds, dt, corner_margin, settings.palette.colors());
+            if corner_hit {
+                let center = logo.center(logo_width, logo_height);
+                println!(
+                    "corner hit at ({:.1}, {:.1}) with speed {:.2}x",
+                    center.x, center.y, settings.speed
+                );
+                corner_hit_count += 1;
             }
+        }
+        if args.collide {
+            resolve_collisions(&mut logos, logo_width, logo_height);
+        }
 
-            if pos.y <= 0.0 {
-                pos.y = 0.0;
-                vel.y = vel.y.abs();
-                bounced_y = true;
-            } else if pos.y + logo_height >= screen_height as f32 {
-                pos.y = screen_height as f32 - logo_height;
-                vel.y = -vel.y.abs();
-                bounced_y = true;
-            }
+        let draw_colors: Vec<(Vector2, Color)> = logos
+            .iter_mut()
+            .map(|logo| (logo.pos, logo.draw_color()))
+            .collect();
 
-            if bounced_x {
-                bounced_x_any = true;
+        if let Some(texture) = trail_texture.as_mut() {
+            let mut mode = rl.begin_texture_mode(&thread, texture);
+            // Scale every existing pixel (color and alpha) down by (1 - trail_fade)
+            // via multiplicative blending, rather than alpha-blending a black
+            // rectangle over it, so the trail decays toward fully transparent
+            // instead of toward opaque black against the zero-alpha window.
+            let decay = ((1.0 - settings.trail_fade) * 255.0).round() as u8;
+            {
+                // result = src * 0 + dst * src (GL_ZERO, GL_SRC_COLOR, GL_FUNC_ADD).
+                // raylib's stock BLEND_MULTIPLIED adds dst * (1 - src_alpha) on top,
+                // which cancels the decay entirely.
+                unsafe { ffi::rlSetBlendFactors(0x0000, 0x0300, 0x8006) };
+                let mut blend = mode.begin_blend_mode(BlendMode::BLEND_CUSTOM);
+                blend.draw_rectangle(
+                    0,
+                    0,
+                    screen_width,
+                    screen_height,
+                    Color::new(decay, decay, decay, decay),
+                );
             }
-            if bounced_y {
-                bounced_y_any = true;
+            for logo in &mut logos {
+                let center = logo.center(logo_width, logo_height);
+                mode.draw_line_v(logo.prev_center, center, Color::new(255, 255, 255, 70));
+                logo.prev_center = center;
             }
+        } else {
+            for logo in &mut logos {
+                logo.prev_center = logo.center(logo_width, logo_height);
+            }
+        }
 
-            let corner_margin = args.corner as f32;
-            let near_top = pos.y <= corner_margin;
-            let near_bottom = pos.y + logo_height >= screen_height as f32 - corner_margin;
-            let near_left = pos.x <= corner_margin;
-            let near_right = pos.x + logo_width >= screen_width as f32 - corner_margin;
-
-            let near_corner = (near_left || near_right) && (near_top || near_bottom);
+        let source = Rectangle::new(0.0, 0.0, screen_width as f32, -(screen_height as f32));
+        let max_corner_flash_frames = logos
+            .iter()
+            .map(|l| l.corner_flash_frames)
+            .max()
+            .unwrap_or(0);
+        let glow_strength = max_corner_flash_frames as f32 / CORNER_FLASH_FRAMES as f32;
 
-            if (bounced_x && bounced_y) || (near_corner && (bounced_x || bounced_y)) {
-                corner_hit = true;
+        if let Some(post) = post.as_mut() {
+            {
+                let mut mode = rl.begin_texture_mode(&thread, &mut post.scene);
+                mode.clear_background(Color::new(0, 0, 0, 0));
+                draw_scene(
+                    &mut mode,
+                    trail_texture.as_ref(),
+                    &logo_texture,
+                    &draw_colors,
+                    logo_scale,
+                    screen_width,
+                    screen_height,
+                );
             }
-        }
 
-        if corner_hit {
-            println!(
-                "corner hit at ({:.1}, {:.1}) with speed {:.2}x",
-                pos.x + logo_width * 0.5,
-                pos.y + logo_height * 0.5,
-                args.speed
-            );
-            logo_color = LogoColor::Gold;
-            corner_flash_frames = CORNER_FLASH_FRAMES;
-            corner_flash_step = 0;
-        } else if bounced_x_any || bounced_y_any {
-            logo_color = random_logo_color(logo_color);
-        }
+            let time = rl.get_time() as f32;
 
-        if bounced_x_any || bounced_y_any {
-            apply_bounce_jitter(&mut vel);
-        }
+            match &mut post.effect {
+                ShaderPipeline::Bloom {
+                    blur_shader,
+                    direction_loc,
+                    texel_size_loc,
+                    ping,
+                    pong,
+                } => {
+                    let texel_size = [1.0 / screen_width as f32, 1.0 / screen_height as f32];
+                    blur_shader.set_shader_value(*texel_size_loc, texel_size);
 
-        let draw_color = if corner_flash_frames > 0 {
-            let flash_color =
-                LogoColor::CORNER_FLASH[corner_flash_step % LogoColor::CORNER_FLASH.len()];
-            corner_flash_frames -= 1;
-            corner_flash_step += 1;
-            flash_color.color()
-        } else {
-            logo_color.color()
-        };
+                    blur_shader.set_shader_value(*direction_loc, [1.0f32, 0.0f32]);
+                    {
+                        let mut mode = rl.begin_texture_mode(&thread, ping);
+                        mode.clear_background(Color::new(0, 0, 0, 0));
+                        let mut shader_mode = mode.begin_shader_mode(blur_shader);
+                        shader_mode.draw_texture_rec(
+                            post.scene.texture(),
+                            source,
+                            Vector2::new(0.0, 0.0),
+                            Color::WHITE,
+                        );
+                    }
 
-        if args.trace {
-            trace_points.push(Vector2::new(
-                pos.x + logo_width * 0.5,
-                pos.y + logo_height * 0.5,
-            ));
+                    blur_shader.set_shader_value(*direction_loc, [0.0f32, 1.0f32]);
+                    {
+                        let mut mode = rl.begin_texture_mode(&thread, pong);
+                        mode.clear_background(Color::new(0, 0, 0, 0));
+                        let mut shader_mode = mode.begin_shader_mode(blur_shader);
+                        shader_mode.draw_texture_rec(
+                            ping.texture(),
+                            source,
+                            Vector2::new(0.0, 0.0),
+                            Color::WHITE,
+                        );
+                    }
+                }
+                ShaderPipeline::ChromaticAberration {
+                    shader,
+                    time_loc,
+                    strength_loc,
+                } => {
+                    shader.set_shader_value(*time_loc, time);
+                    shader.set_shader_value(*strength_loc, glow_strength);
+                }
+            }
         }
 
         let mut d = rl.begin_drawing(&thread);
         d.clear_background(Color::new(0, 0, 0, 0));
-        if args.trace {
-            for segment in trace_points.windows(2) {
-                d.draw_line_v(segment[0], segment[1], Color::new(255, 255, 255, 70));
+
+        match post.as_mut() {
+            Some(post) => match &mut post.effect {
+                ShaderPipeline::Bloom { pong, .. } => {
+                    {
+                        let mut blend = d.begin_blend_mode(BlendMode::BLEND_ALPHA);
+                        blend.draw_texture_rec(
+                            post.scene.texture(),
+                            source,
+                            Vector2::new(0.0, 0.0),
+                            Color::WHITE,
+                        );
+                    }
+                    let glow_alpha = (glow_strength * 255.0) as u8;
+                    if glow_alpha > 0 {
+                        let mut blend = d.begin_blend_mode(BlendMode::BLEND_ADDITIVE);
+                        blend.draw_texture_rec(
+                            pong.texture(),
+                            source,
+                            Vector2::new(0.0, 0.0),
+                            Color::new(255, 255, 255, glow_alpha),
+                        );
+                    }
+                }
+                ShaderPipeline::ChromaticAberration { shader, .. } => {
+                    let mut shader_mode = d.begin_shader_mode(shader);
+                    shader_mode.draw_texture_rec(
+                        post.scene.texture(),
+                        source,
+                        Vector2::new(0.0, 0.0),
+                        Color::WHITE,
+                    );
+                }
+            },
+            None => {
+                draw_scene(
+                    &mut d,
+                    trail_texture.as_ref(),
+                    &logo_texture,
+                    &draw_colors,
+                    logo_scale,
+                    screen_width,
+                    screen_height,
+                );
             }
         }
-        d.draw_texture_ex(&logo_texture, pos, 0.0, logo_scale, draw_color);
+
+        if let Some(corner) = args.hud {
+            let stats = HudStats {
+                corner_hits: corner_hit_count,
+                runtime_secs: d.get_time() as f32 - start_time,
+                speed: settings.speed,
+                fps: d.get_fps() as u32,
+            };
+            draw_hud(&mut d, corner, &stats, screen_width, screen_height);
+        }
     }
+
+    save_settings(&settings);
 }